@@ -0,0 +1,128 @@
+use crate::{
+    ServerError, ServerErrorBehaviour, ServerErrorContext, ServerErrorTag, ServerErrorTrait,
+};
+
+// Aggregates multiple child `ServerError`s into one, for parents that fan work
+// out across threads and need to collect every child failure rather than
+// discarding them (as `MultithreadingError` does).
+#[derive(Debug)]
+pub struct AggregateError {
+    context: String,
+    message: String,
+    errors: Vec<ServerError>,
+    context_frames: Vec<String>,
+}
+
+impl AggregateError {
+    // Builds an aggregate from the collected child errors. The rendered
+    // `message()` enumerates every contained error so the full set is visible
+    // in logs.
+    #[allow(clippy::new_ret_no_self)]
+    #[track_caller]
+    pub fn new(errors: Vec<ServerError>) -> ServerError {
+        let mut message = format!("{} error(s) occurred:", errors.len());
+        for (i, error) in errors.iter().enumerate() {
+            message.push_str(&format!("\n  {}. {}", i + 1, error.message()));
+        }
+        Box::new(AggregateError {
+            context: ServerErrorContext::Backtrace.capture(),
+            message,
+            errors,
+            context_frames: Vec::new(),
+        })
+    }
+
+    // Folds an iterator of `Result`s into a single outcome: returns `Ok` with
+    // every value if all succeeded, or an `AggregateError` collecting every
+    // `Err` otherwise. This is the "aggregate" pattern for multi-task error
+    // collection.
+    #[track_caller]
+    pub fn collect<T, I>(results: I) -> Result<Vec<T>, ServerError>
+    where
+        I: IntoIterator<Item = Result<T, ServerError>>,
+    {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+        for result in results {
+            match result {
+                Ok(value) => values.push(value),
+                Err(error) => errors.push(error),
+            }
+        }
+        if errors.is_empty() {
+            Ok(values)
+        } else {
+            Err(AggregateError::new(errors))
+        }
+    }
+}
+
+impl ServerErrorTrait for AggregateError {
+    // The most severe behaviour among the children, so forwarding the aggregate
+    // routes as aggressively as its worst member demands.
+    fn behaviour(&self) -> ServerErrorBehaviour {
+        self.errors
+            .iter()
+            .map(|error| error.behaviour())
+            .max_by_key(severity_rank)
+            .unwrap_or(ServerErrorBehaviour::ReturnInternalServerError)
+    }
+    // `Critical` if any child is critical.
+    fn tag(&self) -> ServerErrorTag {
+        if self
+            .errors
+            .iter()
+            .any(|error| error.tag() == ServerErrorTag::Critical)
+        {
+            ServerErrorTag::Critical
+        } else {
+            ServerErrorTag::None
+        }
+    }
+    fn context(&self) -> &String {
+        &self.context
+    }
+    fn message(&self) -> &String {
+        &self.message
+    }
+    fn debug(&self) -> Option<&String> {
+        None
+    }
+    fn cause(&self) -> Option<&ServerError> {
+        None
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn context_frames(&self) -> &Vec<String> {
+        &self.context_frames
+    }
+    fn push_context_frame(&mut self, frame: String) {
+        self.context_frames.push(frame);
+    }
+}
+
+// Required by the `std::error::Error` supertrait on `ServerErrorTrait`; the
+// child errors are enumerated in `message()`, so there is no single `cause`.
+impl std::fmt::Display for AggregateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        (self as &dyn ServerErrorTrait).fmt_chain(f)
+    }
+}
+
+impl std::error::Error for AggregateError {}
+
+// Ranks behaviours by severity so `AggregateError::behaviour()` can pick the
+// worst child. `ReturnInternalServerError` (and critical errors, which always
+// use it) outrank client-forwarding behaviours.
+fn severity_rank(behaviour: &ServerErrorBehaviour) -> u8 {
+    match behaviour {
+        ServerErrorBehaviour::ForwardToClient => 0,
+        ServerErrorBehaviour::LogWarningForwardToClient => 1,
+        ServerErrorBehaviour::LogWarningSendFixedMsgToClient(_) => 2,
+        ServerErrorBehaviour::LogErrorForwardToClient => 3,
+        ServerErrorBehaviour::LogErrorSendFixedMsgToClient(_) => 4,
+        ServerErrorBehaviour::ReturnUnauthorized => 5,
+        ServerErrorBehaviour::ReturnInternalServerError => 6,
+    }
+}
@@ -0,0 +1,3 @@
+// Fixed message surfaced to clients in place of internal error details, used by
+// the `define_client_error!` behaviour.
+pub const CLIENT_ERROR_MSG: &str = "An error occurred while processing your request.";
@@ -0,0 +1,78 @@
+use crate::{define_client_error, define_internal_error, ServerError};
+
+// Auto-generated wrappers for foreign errors.
+// --------------------------------------------------
+
+// Used by the blanket `From` impl and `ResultExt::into_internal`: a foreign
+// error that bubbled up unexpectedly is treated as an internal server error.
+define_internal_error!(ExternalError, "An external error occurred: {source}.", { source: String });
+
+// Used by `ResultExt::into_client` when the caller wants the foreign error
+// treated as a client error rather than an internal fault. The error is logged
+// and the client receives the fixed `CLIENT_ERROR_MSG`.
+define_client_error!(ExternalClientError, "An external error occurred: {source}.", { source: String });
+
+// Blanket conversion so `?` works on foreign errors inside functions returning
+// `Result<T, ServerError>`. The original error is kept as the `debug` payload,
+// and the call site is captured via `#[track_caller]`.
+impl<E> From<E> for ServerError
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    #[track_caller]
+    fn from(err: E) -> ServerError {
+        ExternalError::with_debug(err.to_string(), &err)
+    }
+}
+
+// Combinators for cases where the desired behaviour differs from the default
+// `From` mapping, letting callers pick the mapping explicitly.
+pub trait ResultExt<T> {
+    // Map the error into an internal server error (the same mapping as `?`).
+    fn into_internal(self) -> Result<T, ServerError>;
+    // Map the error into a client error, which is logged and answered with the
+    // fixed `CLIENT_ERROR_MSG`.
+    fn into_client(self) -> Result<T, ServerError>;
+}
+
+impl<T, E> ResultExt<T> for Result<T, E>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    #[track_caller]
+    fn into_internal(self) -> Result<T, ServerError> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(err) => Err(ExternalError::with_debug(err.to_string(), &err)),
+        }
+    }
+
+    #[track_caller]
+    fn into_client(self) -> Result<T, ServerError> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(err) => Err(ExternalClientError::with_debug(err.to_string(), &err)),
+        }
+    }
+}
+
+// Anyhow-style context layering: attach human-readable context as an error
+// bubbles up, without defining a new error type at each layer. The frame is
+// pushed onto the existing error (see `ServerErrorTrait::push_context_frame`),
+// leaving its `behaviour()`/`tag()` routing untouched.
+pub trait ContextExt {
+    fn context<C: Into<String>>(self, frame: C) -> Self;
+}
+
+impl ContextExt for ServerError {
+    fn context<C: Into<String>>(mut self, frame: C) -> Self {
+        self.push_context_frame(frame.into());
+        self
+    }
+}
+
+impl<T> ContextExt for Result<T, ServerError> {
+    fn context<C: Into<String>>(self, frame: C) -> Self {
+        self.map_err(|err| err.context(frame))
+    }
+}
@@ -1,8 +1,14 @@
+mod aggregate_error;
 mod constants;
+mod conversions;
 mod macros;
 mod server_error;
 mod standard_errors;
 
+pub use aggregate_error::AggregateError;
 pub use constants::*;
-pub use server_error::{ServerError, ServerErrorBehaviour, ServerErrorTrait};
+pub use conversions::{ContextExt, ExternalClientError, ExternalError, ResultExt};
+pub use server_error::{
+    ServerError, ServerErrorBehaviour, ServerErrorContext, ServerErrorTag, ServerErrorTrait,
+};
 pub use standard_errors::*;
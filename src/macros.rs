@@ -13,16 +13,23 @@ macro_rules! define_server_error {
             context: String,
             message: String,
             debug: Option<String>,
+            source: Option<$crate::ServerError>,
+            context_frames: Vec<String>,
         }
 
         impl $name {
-            #[allow(dead_code)]
+            // `new` intentionally returns a boxed `ServerError` rather than
+            // `Self`, so the type-specific constructor can be used directly in
+            // `Result<_, ServerError>` positions.
+            #[allow(dead_code, clippy::new_ret_no_self)]
             #[track_caller]
             pub fn new($($arg: $argtype),*) -> $crate::ServerError {
                 Box::new($name {
                     context: $context_type.capture(),
                     message: format!($msg, $($arg = $arg),*),
                     debug: None,
+                    source: None,
+                    context_frames: Vec::new(),
                 })
             }
 
@@ -36,6 +43,43 @@ macro_rules! define_server_error {
                     context: $context_type.capture(),
                     message: format!($msg, $($arg = $arg),*),
                     debug: Some(format!("{:?}", debug)),
+                    source: None,
+                    context_frames: Vec::new(),
+                })
+            }
+
+            // Wraps a lower-level error as the cause of this one, so that log
+            // formatters can walk the full causal chain (see `fmt_chain` on
+            // `dyn ServerErrorTrait`) even when only the top message is
+            // surfaced to the client per `behaviour()`.
+            #[allow(dead_code)]
+            #[track_caller]
+            pub fn with_source(
+                $($arg: $argtype,)*
+                source: $crate::ServerError,
+            ) -> $crate::ServerError {
+                Box::new($name {
+                    context: $context_type.capture(),
+                    message: format!($msg, $($arg = $arg),*),
+                    debug: None,
+                    source: Some(source),
+                    context_frames: Vec::new(),
+                })
+            }
+
+            #[allow(dead_code)]
+            #[track_caller]
+            pub fn with_source_and_debug<D>(
+                $($arg: $argtype,)*
+                source: $crate::ServerError,
+                debug: &D,
+            ) -> $crate::ServerError where D: std::fmt::Debug {
+                Box::new($name {
+                    context: $context_type.capture(),
+                    message: format!($msg, $($arg = $arg),*),
+                    debug: Some(format!("{:?}", debug)),
+                    source: Some(source),
+                    context_frames: Vec::new(),
                 })
             }
         }
@@ -56,6 +100,37 @@ macro_rules! define_server_error {
             fn debug(&self) -> Option<&String> {
                 self.debug.as_ref()
             }
+            fn cause(&self) -> Option<&$crate::ServerError> {
+                self.source.as_ref()
+            }
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+            fn context_frames(&self) -> &Vec<String> {
+                &self.context_frames
+            }
+            fn push_context_frame(&mut self, frame: String) {
+                self.context_frames.push(frame);
+            }
+        }
+
+        // Required by the `std::error::Error` supertrait on `ServerErrorTrait`.
+        // `Display` delegates to the shared `fmt_chain` helper so the formatting
+        // logic lives in one place, and `Error::source` exposes the cause for
+        // std-style chain walking.
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                (self as &dyn $crate::ServerErrorTrait).fmt_chain(f)
+            }
+        }
+
+        impl std::error::Error for $name {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                $crate::ServerErrorTrait::cause(self).map(|c| {
+                    let cause: &(dyn std::error::Error + 'static) = c.as_ref();
+                    cause
+                })
+            }
         }
     };
 }
@@ -72,7 +147,7 @@ macro_rules! define_internal_error {
             { $($arg : $argtype),* },
             // Since internal errors usually indicate more serious issues,
             // enable more expensive context to facilitate debugging.
-            $crate::ServerErrorContext::Full,
+            $crate::ServerErrorContext::Backtrace,
             $crate::ServerErrorBehaviour::ReturnInternalServerError,
             $crate::ServerErrorTag::None
         );
@@ -91,7 +166,7 @@ macro_rules! define_critical_error {
             { $($arg : $argtype),* },
             // Since critical errors indicate serious and rare issues, enable
             // more expensive context to facilitate debugging.
-            $crate::ServerErrorContext::Full,
+            $crate::ServerErrorContext::Backtrace,
             $crate::ServerErrorBehaviour::ReturnInternalServerError,
             $crate::ServerErrorTag::Critical
         );
@@ -111,7 +186,7 @@ macro_rules! define_client_error {
             // This error type is usually less serious, and mainly indicates an
             // issue with client code (not server code), so use less expensive
             // context.
-            $crate::ServerErrorContext::Partial,
+            $crate::ServerErrorContext::Location,
             $crate::ServerErrorBehaviour::LogErrorSendFixedMsgToClient($crate::CLIENT_ERROR_MSG),
             $crate::ServerErrorTag::None
         );
@@ -130,7 +205,7 @@ macro_rules! define_sensitive_error {
             { $($arg : $argtype),* },
             // To avoid leaking implementation details for sensitive errors,
             // don't provide execution context.
-            $crate::ServerErrorContext::None,
+            $crate::ServerErrorContext::Omit,
             $crate::ServerErrorBehaviour::ReturnUnauthorized,
             $crate::ServerErrorTag::None
         );
@@ -149,7 +224,7 @@ macro_rules! define_user_error {
             { $($arg : $argtype),* },
             // This error type is usually not indicative of an error with the
             // code, so use less expensive context.
-            $crate::ServerErrorContext::Partial,
+            $crate::ServerErrorContext::Location,
             $crate::ServerErrorBehaviour::LogWarningForwardToClient,
             $crate::ServerErrorTag::None
         );
@@ -168,7 +243,7 @@ macro_rules! define_temporary_error {
             { $($arg : $argtype),* },
             // This error type is usually not indicative of an error with the
             // code, so use less expensive context.
-            $crate::ServerErrorContext::Partial,
+            $crate::ServerErrorContext::Location,
             $crate::ServerErrorBehaviour::LogWarningForwardToClient,
             $crate::ServerErrorTag::None
         );
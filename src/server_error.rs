@@ -22,35 +22,140 @@ pub enum ServerErrorContext {
     Omit,
     // Uses panic::Location::caller(), which is cheaper to compute.
     Location,
-    // Uses backtrace::Backtrace::force_capture(), which is expensive to
-    // compute, but contains more information.
+    // Uses backtrace::Backtrace::new(), which is expensive to compute, but
+    // contains more information.
     Backtrace,
 }
 
-pub trait ServerErrorTrait: std::fmt::Debug + Send + Sync + 'static {
+impl ServerErrorContext {
+    // Captures execution context for an error at the call site. Because this is
+    // `#[track_caller]` and the generated constructors are too, `Location`
+    // resolves to the site where the error was created, not this function.
+    #[track_caller]
+    pub fn capture(&self) -> String {
+        match self {
+            // Sensitive errors omit context to avoid leaking implementation
+            // details.
+            ServerErrorContext::Omit => String::new(),
+            ServerErrorContext::Location => {
+                let location = std::panic::Location::caller();
+                format!(
+                    "{}:{}:{}",
+                    location.file(),
+                    location.line(),
+                    location.column()
+                )
+            }
+            // A full backtrace is expensive, so it is gated behind the
+            // `backtrace` feature (declared in Cargo.toml alongside the optional
+            // `backtrace` dependency); builds without the feature fall back to
+            // the cheap caller location.
+            ServerErrorContext::Backtrace => {
+                #[cfg(feature = "backtrace")]
+                {
+                    format!("{:?}", backtrace::Backtrace::new())
+                }
+                #[cfg(not(feature = "backtrace"))]
+                {
+                    let location = std::panic::Location::caller();
+                    format!(
+                        "{}:{}:{}",
+                        location.file(),
+                        location.line(),
+                        location.column()
+                    )
+                }
+            }
+        }
+    }
+}
+
+pub trait ServerErrorTrait: std::error::Error + Send + Sync + 'static {
     fn behaviour(&self) -> ServerErrorBehaviour;
     fn tag(&self) -> ServerErrorTag;
     fn context(&self) -> &String;
     fn message(&self) -> &String;
     fn debug(&self) -> Option<&String>;
+    // The lower-level error that caused this one, if any. Follow this to walk
+    // the full causal chain (see `fmt_chain` below). Named `cause` rather than
+    // `source` to avoid colliding with the `std::error::Error::source`
+    // supertrait method.
+    fn cause(&self) -> Option<&ServerError>;
+    // Returns this error as `&dyn Any`, enabling the downcast helpers below to
+    // recover the concrete error type at a boundary.
+    fn as_any(&self) -> &dyn std::any::Any;
+    // Human-readable context frames attached as the error bubbles up, innermost
+    // first. Rendered in reverse by `Display` (see below).
+    fn context_frames(&self) -> &Vec<String>;
+    // Pushes a context frame onto the error (used by the `.context(..)`
+    // combinator in `ContextExt`).
+    fn push_context_frame(&mut self, frame: String);
 }
 
 pub type ServerError = Box<dyn ServerErrorTrait>;
 
-impl std::fmt::Display for dyn ServerErrorTrait {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+// Downcasting helpers mirroring the `dyn Error` API from std, so adapter layers
+// can recover a concrete error type (e.g. to choose an HTTP status code)
+// instead of string-matching the message.
+impl dyn ServerErrorTrait {
+    // Returns `true` if the boxed error is of concrete type `T`.
+    pub fn is<T: ServerErrorTrait>(&self) -> bool {
+        self.as_any().is::<T>()
+    }
+
+    // Returns a reference to the concrete error if it is of type `T`.
+    pub fn downcast_ref<T: ServerErrorTrait>(&self) -> Option<&T> {
+        self.as_any().downcast_ref::<T>()
+    }
+
+    // Attempts to recover the concrete error, returning the original boxed
+    // error unchanged if it is not of type `T`.
+    pub fn downcast<T: ServerErrorTrait>(self: Box<Self>) -> Result<Box<T>, ServerError> {
+        if self.is::<T>() {
+            let raw: *mut dyn ServerErrorTrait = Box::into_raw(self);
+            // SAFETY: `is::<T>()` just confirmed the concrete type is `T`.
+            Ok(unsafe { Box::from_raw(raw as *mut T) })
+        } else {
+            Err(self)
+        }
+    }
+
+    // Renders the error for `Display`. Because `ServerErrorTrait` has
+    // `std::error::Error` (and thus `Display`) as a supertrait, the trait object
+    // auto-implements `Display`, so this logic cannot live in a hand-written
+    // `impl Display for dyn ServerErrorTrait` (E0371). Instead it lives here and
+    // each generated per-struct `Display` delegates to it.
+    pub fn fmt_chain(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // Context frames bubble up innermost-first; print them in reverse as a
+        // "while X / while Y" stack above the root message, leaving behaviour()
+        // and tag() of the underlying error untouched.
+        for frame in self.context_frames().iter().rev() {
+            writeln!(f, "while {}", frame)?;
+        }
         match self.tag() {
             ServerErrorTag::None => {}
             ServerErrorTag::Critical => {
                 write!(f, "{}", "CRITICAL".bold().red())?;
             }
         }
-        write!(f, "{}\n{:#?}", self.message().bold(), self)
+        write!(f, "{}", self.message().bold())?;
+        if let Some(debug) = self.debug() {
+            write!(f, "\n{}", debug)?;
+        }
+        // Walk the causal chain, printing each underlying layer's message
+        // indented beneath the top error. This keeps the full internal chain
+        // visible in logs even when only the top message is forwarded to the
+        // client per behaviour(). The chain is rendered only here, not via a
+        // recursive Debug dump, so logs aren't doubled.
+        let mut cause = ServerErrorTrait::cause(self);
+        while let Some(next) = cause {
+            write!(f, "\n    caused by: {}", next.message())?;
+            cause = ServerErrorTrait::cause(next.as_ref());
+        }
+        Ok(())
     }
 }
 
-impl std::error::Error for dyn ServerErrorTrait {}
-
 // Tests
 // --------------------------------------------------
 
@@ -185,4 +290,27 @@ mod tests {
         );
         assert_eq!(error.tag(), ServerErrorTag::None);
     }
+
+    #[test]
+    fn test_context_points_to_call_site() {
+        // Client errors capture caller location, so the context should name
+        // this source file and the line the error was created on.
+        define_client_error!(ClientError, "A client error occurred.");
+        let line = line!() + 1;
+        let error = ClientError::new();
+
+        assert!(error.context().contains("server_error.rs"));
+        assert!(error.context().contains(&line.to_string()));
+    }
+
+    #[cfg(feature = "backtrace")]
+    #[test]
+    fn test_backtrace_context_points_to_call_site() {
+        // Internal errors capture a full backtrace when the feature is enabled;
+        // that backtrace should contain a frame pointing at this source file.
+        define_internal_error!(InternalError, "An internal error occurred.");
+        let error = InternalError::new();
+
+        assert!(error.context().contains("server_error.rs"));
+    }
 }